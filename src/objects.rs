@@ -75,6 +75,19 @@ define_opaque_object!(PhysicsRigidBodyTag, bodies);
 define_opaque_object!(PhysicsAreaTag, areas);
 define_opaque_object!(PhysicsShapeTag, shapes);
 define_opaque_object!(PhysicsJointTag, joints);
+define_opaque_object!(PhysicsMaterialTag, materials);
+
+/// Identifies a physics object that can be returned by a query.
+///
+/// The world queries (like the ray cast) can hit both rigid bodies and areas, this enum allows to
+/// know which kind of object was hit.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PhysicsObjectTag {
+    /// The object is a rigid body.
+    RigidBody(PhysicsRigidBodyTag),
+    /// The object is an area.
+    Area(PhysicsAreaTag),
+}
 
 /// This is used only to perform the setup of these storages.
 ///
@@ -84,6 +97,7 @@ pub(crate) type PhysicsSetupStorages<'a> = (
     amethyst_core::ecs::ReadStorage<'a, PhysicsHandle<PhysicsAreaTag>>,
     amethyst_core::ecs::ReadStorage<'a, PhysicsHandle<PhysicsShapeTag>>,
     amethyst_core::ecs::ReadStorage<'a, PhysicsHandle<PhysicsJointTag>>,
+    amethyst_core::ecs::ReadStorage<'a, PhysicsHandle<PhysicsMaterialTag>>,
 );
 
 /// This trait must be implemented for each structure that want to use the PhysicsHandle.
@@ -200,6 +214,8 @@ pub struct PhysicsGarbageCollector {
     pub shapes: Vec<PhysicsShapeTag>,
     /// List of joints no mor used.
     pub joints: Vec<PhysicsJointTag>,
+    /// List of materials no more used.
+    pub materials: Vec<PhysicsMaterialTag>,
 }
 
 impl Default for PhysicsGarbageCollector {
@@ -209,6 +225,7 @@ impl Default for PhysicsGarbageCollector {
             areas: Vec::new(),
             shapes: Vec::new(),
             joints: Vec::new(),
+            materials: Vec::new(),
         }
     }
 }
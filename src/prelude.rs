@@ -3,12 +3,16 @@
 pub use crate::{
     objects::{
         CollisionGroup, PhysicsAreaTag, PhysicsAttachment, PhysicsGarbageCollector, PhysicsHandle,
-        PhysicsJointTag, PhysicsRigidBodyTag, PhysicsShapeTag, PhysicsTag,
+        PhysicsJointTag, PhysicsMaterialTag, PhysicsObjectTag, PhysicsRigidBodyTag,
+        PhysicsShapeTag, PhysicsTag,
     },
     servers::{
-        AreaPhysicsServerTrait, BodyMode, ContactEvent, JointDesc, JointPhysicsServerTrait,
-        JointPosition, OverlapEvent, PhysicsWorld, RBodyPhysicsServerTrait, RigidBodyDesc,
-        ShapeDesc, ShapePhysicsServerTrait, WorldPhysicsServerTrait,
+        AreaGravity, AreaOverrideMode, AreaPhysicsServerTrait, BodyMode, CombineMode, ContactEvent,
+        ContactPhase, FluidDesc, JointDesc, JointPhysicsServerTrait, JointPosition, MaterialDesc,
+        MaterialPhysicsServerTrait, MotionTestResult, OverlapEvent, PhysicsWorld,
+        PointProjectionResult, RBodyPhysicsServerTrait, RayCastResult, RigidBodyDesc,
+        ShapeCastResult, ShapeDesc, ShapePhysicsServerTrait, SubShapeMaterial,
+        WorldPhysicsServerTrait, WorldSettings,
     },
     PhysicsTime,
 };
@@ -1,5 +1,5 @@
 use amethyst_core::ecs::Entity;
-use amethyst_core::math::Isometry3;
+use amethyst_core::math::{convert, one, zero, Isometry3, Point3, Unit, Vector3};
 
 use crate::{objects::*, PtReal};
 
@@ -10,7 +10,7 @@ pub trait AreaPhysicsServerTrait<N: PtReal> {
     /// Create an Area and return its handle.
     /// The PhysicsHandle returned can be safely cloned.
     /// When all instances of this Handle are dropped the Area is Dropped automatically.
-    fn create(&self, area_desc: &AreaDesc) -> PhysicsHandle<PhysicsAreaTag>;
+    fn create(&self, area_desc: &AreaDesc<N>) -> PhysicsHandle<PhysicsAreaTag>;
 
     /// Set the entity which holds this body.
     fn set_entity(&self, area_tag: PhysicsAreaTag, index: Option<Entity>);
@@ -31,6 +31,11 @@ pub trait AreaPhysicsServerTrait<N: PtReal> {
     /// Get the shape of the area
     fn shape(&self, area_tag: PhysicsAreaTag) -> Option<PhysicsShapeTag>;
 
+    /// Get the world space bounding box of the area, as `(mins, maxs)`.
+    ///
+    /// Returns `None` when the area has no shape.
+    fn aabb(&self, area_tag: PhysicsAreaTag) -> Option<(Point3<N>, Point3<N>)>;
+
     /// Set the transformation of the area.
     fn set_transform(&self, area_tag: PhysicsAreaTag, transf: &Isometry3<N>);
 
@@ -49,30 +54,244 @@ pub trait AreaPhysicsServerTrait<N: PtReal> {
     /// Get the groups this body collide with.
     fn collide_with(&self, area_tag: PhysicsAreaTag) -> Vec<CollisionGroup>;
 
+    /// Set if the area is monitoring.
+    ///
+    /// When disabled, the area doesn't produce any `OverlapEvent`, and the functions
+    /// `overlapping_bodies` and `overlapping_areas` return nothing; the area overrides are still
+    /// applied.
+    /// It's useful to temporarily disable an area without dropping it.
+    fn set_monitoring(&self, area_tag: PhysicsAreaTag, monitoring: bool);
+
+    /// Returns true if the area is monitoring.
+    fn monitoring(&self, area_tag: PhysicsAreaTag) -> bool;
+
+    /// Set if the area can be detected by the other areas.
+    fn set_monitorable(&self, area_tag: PhysicsAreaTag, monitorable: bool);
+
+    /// Returns true if the area can be detected by the other areas.
+    fn monitorable(&self, area_tag: PhysicsAreaTag) -> bool;
+
+    /// Set if the area detects the other areas.
+    ///
+    /// When enabled, the areas that enter and exit this area are reported through the events
+    /// `OverlapEvent::AreaEnter` and `OverlapEvent::AreaExit`.
+    fn set_monitor_areas(&self, area_tag: PhysicsAreaTag, monitor_areas: bool);
+
+    /// Returns true if the area detects the other areas.
+    fn monitor_areas(&self, area_tag: PhysicsAreaTag) -> bool;
+
+    /// Set how the area overrides are applied to the bodies inside it.
+    fn set_override_mode(&self, area_tag: PhysicsAreaTag, mode: AreaOverrideMode);
+
+    /// Get the area override mode.
+    fn override_mode(&self, area_tag: PhysicsAreaTag) -> AreaOverrideMode;
+
+    /// Set the priority of the area.
+    ///
+    /// When a body is inside many areas, their overrides are applied from the highest priority to
+    /// the lowest.
+    fn set_priority(&self, area_tag: PhysicsAreaTag, priority: i32);
+
+    /// Get the priority of the area.
+    fn priority(&self, area_tag: PhysicsAreaTag) -> i32;
+
+    /// Set the gravity applied to the dynamic bodies inside the area.
+    ///
+    /// The area gravity is combined, following the area `AreaOverrideMode`, with the body gravity:
+    /// that is its gravity override, when set, otherwise the world gravity. The body gravity scale
+    /// is applied at the end, to the combined gravity.
+    /// Passing None, the area doesn't override the gravity.
+    fn set_gravity(&self, area_tag: PhysicsAreaTag, gravity: Option<AreaGravity<N>>);
+
+    /// Get the gravity applied to the dynamic bodies inside the area.
+    fn gravity(&self, area_tag: PhysicsAreaTag) -> Option<AreaGravity<N>>;
+
+    /// Set the linear damping applied to the dynamic bodies inside the area.
+    ///
+    /// Passing None, the area doesn't override the linear damping.
+    fn set_linear_damping(&self, area_tag: PhysicsAreaTag, damping: Option<N>);
+
+    /// Get the linear damping applied to the dynamic bodies inside the area.
+    fn linear_damping(&self, area_tag: PhysicsAreaTag) -> Option<N>;
+
+    /// Set the angular damping applied to the dynamic bodies inside the area.
+    ///
+    /// Passing None, the area doesn't override the angular damping.
+    fn set_angular_damping(&self, area_tag: PhysicsAreaTag, damping: Option<N>);
+
+    /// Get the angular damping applied to the dynamic bodies inside the area.
+    fn angular_damping(&self, area_tag: PhysicsAreaTag) -> Option<N>;
+
+    /// Set the area as a fluid volume.
+    ///
+    /// The physics backend applies to each dynamic body inside the area a buoyancy force, proportional
+    /// to the volume of its shape that is under the fluid surface, and a drag proportional to the
+    /// submerged fraction of the body.
+    /// Passing None, the area is not a fluid.
+    fn set_fluid(&self, area_tag: PhysicsAreaTag, fluid: Option<FluidDesc<N>>);
+
+    /// Get the fluid description of the area.
+    fn fluid(&self, area_tag: PhysicsAreaTag) -> Option<FluidDesc<N>>;
+
     /// Returns the list of events occurred in the last step.
     /// Is mandatory check this array each sub step to be sure to not miss any event.
     fn overlap_events(&self, area_tag: PhysicsAreaTag) -> Vec<OverlapEvent>;
+
+    /// Returns all the bodies that are actually overlapping the area.
+    ///
+    /// Unlike `overlap_events`, this function returns the full state, so it doesn't matter when
+    /// the bodies entered.
+    fn overlapping_bodies(
+        &self,
+        area_tag: PhysicsAreaTag,
+    ) -> Vec<(PhysicsRigidBodyTag, Option<Entity>)>;
+
+    /// Returns all the areas that are actually overlapping the area.
+    ///
+    /// It's filled only when `monitor_areas` is enabled.
+    fn overlapping_areas(&self, area_tag: PhysicsAreaTag) -> Vec<(PhysicsAreaTag, Option<Entity>)>;
 }
 
 /// This structure holds all information about the Rigid body before it is created.
 #[derive(Debug)]
-pub struct AreaDesc {
+pub struct AreaDesc<N: PtReal> {
     /// Collision Groups this Rigid Body belong.
     pub belong_to: Vec<CollisionGroup>,
     /// Collide with groups.
     pub collide_with: Vec<CollisionGroup>,
+    /// Produce the overlap events.
+    pub monitoring: bool,
+    /// Can be detected by the other areas.
+    pub monitorable: bool,
+    /// Detect the other areas.
+    pub monitor_areas: bool,
+    /// How the overrides are applied.
+    pub override_mode: AreaOverrideMode,
+    /// The area priority, the overrides of the highest priority areas are applied first.
+    pub priority: i32,
+    /// Gravity override, None to not override it.
+    pub gravity: Option<AreaGravity<N>>,
+    /// Linear damping override, None to not override it.
+    pub linear_damping: Option<N>,
+    /// Angular damping override, None to not override it.
+    pub angular_damping: Option<N>,
+    /// Fluid volume, None if the area is not a fluid.
+    pub fluid: Option<FluidDesc<N>>,
 }
 
 /// Initialize the description with default values:
 /// ```ignore
 /// belong_to: vec(1),
 /// collide_with: vec(1),
+/// monitoring: true,
+/// monitorable: true,
+/// monitor_areas: false,
+/// override_mode: AreaOverrideMode::Add,
+/// priority: 0,
+/// gravity: None,
+/// linear_damping: None,
+/// angular_damping: None,
+/// fluid: None,
 /// ```
-impl Default for AreaDesc {
+impl<N: PtReal> Default for AreaDesc<N> {
     fn default() -> Self {
         AreaDesc {
             belong_to: vec![CollisionGroup::default()],
             collide_with: vec![CollisionGroup::default()],
+            monitoring: true,
+            monitorable: true,
+            monitor_areas: false,
+            override_mode: AreaOverrideMode::default(),
+            priority: 0,
+            gravity: None,
+            linear_damping: None,
+            angular_damping: None,
+            fluid: None,
+        }
+    }
+}
+
+/// The way the area overrides are applied to the bodies inside it.
+///
+/// The overrides are computed starting from the world and body values, then the areas are
+/// processed from the highest priority to the lowest.
+///
+/// The modes map to the Godot ones as follows: `Add` is Godot `Combine`, `Replace` is Godot
+/// `Replace`, and `ReplaceCombine` is Godot `Replace Combine`.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum AreaOverrideMode {
+    /// The area values are added to the values computed so far, then the lower priority areas are processed.
+    Add,
+    /// The area values replace the values computed so far, and the lower priority areas are ignored.
+    Replace,
+    /// The area values replace the values computed so far, then the lower priority areas are processed.
+    ReplaceCombine,
+}
+
+impl Default for AreaOverrideMode {
+    fn default() -> Self {
+        AreaOverrideMode::Add
+    }
+}
+
+/// The gravity applied by an area.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum AreaGravity<N: PtReal> {
+    /// A constant gravity, in world space.
+    ///
+    /// Like the world gravity, it doesn't rotate with the area.
+    /// To create a zero gravity area, use a zero vector with the `AreaOverrideMode::Replace` or
+    /// `AreaOverrideMode::ReplaceCombine` mode; with `AreaOverrideMode::Add` the gravity is unchanged.
+    Directional(Vector3<N>),
+    /// A gravity pointing toward a point, like the one of a planet.
+    Point {
+        /// The gravity center, in area local space, so it follows the area when it moves.
+        center: Point3<N>,
+        /// The gravity acceleration, at distance 1 from the center.
+        strength: N,
+        /// The acceleration decreases as `strength / distance.powf(falloff)`; use 0 for a constant
+        /// acceleration, and 2 for a physically correct one.
+        ///
+        /// The gravity direction is undefined at the center, so no gravity is applied to a body
+        /// exactly at the center.
+        falloff: N,
+    },
+}
+
+/// This structure holds the fluid properties of an area.
+///
+/// The buoyancy force of a body is `density * submerged_volume * gravity`, in the opposite direction
+/// of the gravity that affects the body; and it's applied at the center of the submerged volume.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct FluidDesc<N: PtReal> {
+    /// The fluid density.
+    pub density: N,
+    /// The fluid surface normal, in area local space.
+    pub surface_normal: Unit<Vector3<N>>,
+    /// The distance of the fluid surface from the area origin, along the surface normal.
+    pub surface_height: N,
+    /// The linear drag coefficient.
+    pub linear_drag: N,
+    /// The angular drag coefficient.
+    pub angular_drag: N,
+}
+
+/// Initialize the description with default values:
+/// ```ignore
+/// density: 1000.0,
+/// surface_normal: Vector3::y_axis(),
+/// surface_height: 0.0,
+/// linear_drag: 1.0,
+/// angular_drag: 1.0,
+/// ```
+impl<N: PtReal> Default for FluidDesc<N> {
+    fn default() -> Self {
+        FluidDesc {
+            density: convert(1000.0),
+            surface_normal: Vector3::y_axis(),
+            surface_height: zero(),
+            linear_drag: one(),
+            angular_drag: one(),
         }
     }
 }
@@ -86,4 +305,12 @@ pub enum OverlapEvent {
     Enter(PhysicsRigidBodyTag, Option<Entity>),
     /// Overlap event called when the overlap ends.
     Exit(PhysicsRigidBodyTag, Option<Entity>),
+    /// Overlap event called when the overlap with another area starts.
+    ///
+    /// It's reported only when `monitor_areas` is enabled.
+    AreaEnter(PhysicsAreaTag, Option<Entity>),
+    /// Overlap event called when the overlap with another area ends.
+    ///
+    /// It's reported only when `monitor_areas` is enabled.
+    AreaExit(PhysicsAreaTag, Option<Entity>),
 }
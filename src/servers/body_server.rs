@@ -30,23 +30,143 @@ pub trait RBodyPhysicsServerTrait<N: crate::PtReal> {
     /// Passing None, will leave the RigidBody without any shape.
     ///
     /// You can create a shape, using the function `ShapeServer::create`.
+    ///
+    /// When the body uses a density, its mass and inertia are recomputed from the new shape.
     fn set_shape(&self, body_tag: PhysicsRigidBodyTag, shape_tag: Option<PhysicsShapeTag>);
 
     /// Get the shape of the body
     fn shape(&self, body_tag: PhysicsRigidBodyTag) -> Option<PhysicsShapeTag>;
 
+    /// Get the world space bounding box of the body, as `(mins, maxs)`.
+    ///
+    /// Returns `None` when the body has no shape.
+    fn aabb(&self, body_tag: PhysicsRigidBodyTag) -> Option<(Point3<N>, Point3<N>)>;
+
     /// Set the transformation of the body.
+    ///
+    /// The body is woken up, so its new transformation is synchronized even if it was sleeping.
     fn set_transform(&self, body: PhysicsRigidBodyTag, transf: &Isometry3<N>);
 
     /// Get the actual transformation of the body.
     fn transform(&self, body_tag: PhysicsRigidBodyTag) -> Isometry3<N>;
 
+    /// Move the kinematic body to the target transformation, during the next step.
+    ///
+    /// Unlike `set_transform`, that teleports the body, the physics backend computes the linear and
+    /// angular velocity needed to reach the target at the end of the next step; in this way the
+    /// bodies that are touching it are carried along.
+    ///
+    /// This function is meant for bodies in `BodyMode::Kinematic`, like moving platforms or doors;
+    /// on a body in any other mode, it behaves like `set_transform`.
+    /// The body is woken up.
+    fn move_kinematic(&self, body_tag: PhysicsRigidBodyTag, target: &Isometry3<N>);
+
+    /// Test if the body, placed at `from`, can move along `motion` without colliding.
+    ///
+    /// The body shape and its `collide_with` groups are used to perform the test; the body is not
+    /// moved.
+    /// The `margin` is the extra distance kept between the body and the other objects.
+    ///
+    /// The areas and the sensor bodies never stop the motion, so only the non sensor rigid bodies
+    /// are reported.
+    ///
+    /// Returns `None` when the full motion can be performed, otherwise the collision information.
+    ///
+    /// This is the primitive to use to implement a kinematic character controller.
+    fn test_motion(
+        &self,
+        body_tag: PhysicsRigidBodyTag,
+        from: &Isometry3<N>,
+        motion: &Vector3<N>,
+        margin: N,
+    ) -> Option<MotionTestResult<N>>;
+
     /// Sets the body mode
     fn set_mode(&self, body_tag: PhysicsRigidBodyTag, mode: BodyMode);
 
     /// Returns the body body
     fn mode(&self, body_tag: PhysicsRigidBodyTag) -> BodyMode;
 
+    /// Set if the body is allowed to sleep.
+    ///
+    /// A sleeping body is not simulated until something wakes it up, that is useful to save CPU.
+    fn set_can_sleep(&self, body_tag: PhysicsRigidBodyTag, can_sleep: bool);
+
+    /// Returns true if the body is allowed to sleep.
+    fn can_sleep(&self, body_tag: PhysicsRigidBodyTag) -> bool;
+
+    /// Returns true if the body is sleeping.
+    fn is_sleeping(&self, body_tag: PhysicsRigidBodyTag) -> bool;
+
+    /// Wake up the body.
+    fn wake_up(&self, body_tag: PhysicsRigidBodyTag);
+
+    /// Put the body to sleep.
+    ///
+    /// The body stays asleep until something wakes it up.
+    fn put_to_sleep(&self, body_tag: PhysicsRigidBodyTag);
+
+    /// Enable or disable the continuous collision detection (CCD) of the body.
+    ///
+    /// The CCD prevents the fast bodies from tunneling through the thin ones.
+    fn set_ccd_enabled(&self, body_tag: PhysicsRigidBodyTag, enabled: bool);
+
+    /// Returns true if the continuous collision detection is enabled.
+    fn ccd_enabled(&self, body_tag: PhysicsRigidBodyTag) -> bool;
+
+    /// Set the motion, in a single step, above which the continuous collision detection is performed.
+    ///
+    /// Passing None, the physics backend default is used.
+    fn set_ccd_motion_threshold(&self, body_tag: PhysicsRigidBodyTag, threshold: Option<N>);
+
+    /// Get the continuous collision detection motion threshold.
+    fn ccd_motion_threshold(&self, body_tag: PhysicsRigidBodyTag) -> Option<N>;
+
+    /// Set the mass of the body
+    ///
+    /// This mass is ignored while the body uses a density.
+    fn set_mass(&self, body_tag: PhysicsRigidBodyTag, mass: N);
+
+    /// Get the effective mass of the body
+    fn mass(&self, body_tag: PhysicsRigidBodyTag) -> N;
+
+    /// Set the density of the body.
+    ///
+    /// When set, the mass and the inertia are computed from the body shape, and recomputed each
+    /// time the shape changes; the `Compound` shapes sum the contributions of their children.
+    /// Passing None, the body uses the mass set with `set_mass`.
+    fn set_density(&self, body_tag: PhysicsRigidBodyTag, density: Option<N>);
+
+    /// Get the density of the body.
+    fn density(&self, body_tag: PhysicsRigidBodyTag) -> Option<N>;
+
+    /// Set the center of mass of the body, in local space.
+    ///
+    /// Passing None, the center of mass is computed from the body shape.
+    fn set_center_of_mass(&self, body_tag: PhysicsRigidBodyTag, center: Option<Point3<N>>);
+
+    /// Get the effective center of mass of the body, in local space.
+    fn center_of_mass(&self, body_tag: PhysicsRigidBodyTag) -> Point3<N>;
+
+    /// Set the principal inertia tensor of the body.
+    ///
+    /// Passing None, the inertia is computed from the body shape and mass.
+    fn set_principal_inertia(&self, body_tag: PhysicsRigidBodyTag, inertia: Option<Vector3<N>>);
+
+    /// Get the effective principal inertia tensor of the body.
+    fn principal_inertia(&self, body_tag: PhysicsRigidBodyTag) -> Vector3<N>;
+
+    /// Set the material of the body.
+    /// Passing None, will leave the body without any material.
+    ///
+    /// When a material is set, it's used in place of the body friction and bounciness.
+    ///
+    /// You can create a material, using the function `MaterialServer::create`.
+    fn set_material(&self, body_tag: PhysicsRigidBodyTag, material_tag: Option<PhysicsMaterialTag>);
+
+    /// Get the material of the body
+    fn material(&self, body_tag: PhysicsRigidBodyTag) -> Option<PhysicsMaterialTag>;
+
     /// Set the friction of the body
     fn set_friction(&self, body_tag: PhysicsRigidBodyTag, friction: N);
 
@@ -59,6 +179,64 @@ pub trait RBodyPhysicsServerTrait<N: crate::PtReal> {
     /// Get the bounciness of the body
     fn bounciness(&self, body_tag: PhysicsRigidBodyTag) -> N;
 
+    /// Set the linear damping of the body
+    fn set_linear_damping(&self, body_tag: PhysicsRigidBodyTag, damping: N);
+
+    /// Get the linear damping of the body
+    fn linear_damping(&self, body_tag: PhysicsRigidBodyTag) -> N;
+
+    /// Set the angular damping of the body
+    fn set_angular_damping(&self, body_tag: PhysicsRigidBodyTag, damping: N);
+
+    /// Get the angular damping of the body
+    fn angular_damping(&self, body_tag: PhysicsRigidBodyTag) -> N;
+
+    /// Set the gravity scale of the body.
+    ///
+    /// The scale is applied to the gravity that affects the body, even when it's overridden.
+    fn set_gravity_scale(&self, body_tag: PhysicsRigidBodyTag, scale: N);
+
+    /// Get the gravity scale of the body.
+    fn gravity_scale(&self, body_tag: PhysicsRigidBodyTag) -> N;
+
+    /// Set the gravity that affects this body, in place of the world gravity.
+    ///
+    /// The gravity of the areas that contain the body is then combined with it.
+    /// Passing None, the body uses the world gravity.
+    fn set_gravity_override(&self, body_tag: PhysicsRigidBodyTag, gravity: Option<Vector3<N>>);
+
+    /// Get the gravity override of the body.
+    fn gravity_override(&self, body_tag: PhysicsRigidBodyTag) -> Option<Vector3<N>>;
+
+    /// Set the body as sensor.
+    ///
+    /// A sensor body reports its contacts, through the function `contact_events`, but doesn't
+    /// produce any collision response.
+    fn set_sensor(&self, body_tag: PhysicsRigidBodyTag, is_sensor: bool);
+
+    /// Returns true if the body is a sensor.
+    fn is_sensor(&self, body_tag: PhysicsRigidBodyTag) -> bool;
+
+    /// Set the body as one way, passing the direction, in local space, along which the other bodies
+    /// can pass through it.
+    ///
+    /// A new contact is ignored when its normal, pointing from this body toward the other one and
+    /// taken in this body local space, points against this direction (negative dot product); so the
+    /// other body collides only when it comes from the allowed side. For example, a jump through
+    /// platform uses `Vector3::y_axis()`: a body jumping from below passes through it, and a body
+    /// falling from above lands on it.
+    /// The velocity of the bodies is not considered; once ignored, the pair of bodies stays ignored
+    /// until they separate.
+    /// Passing None, the body collides from all the sides.
+    fn set_one_way_direction(
+        &self,
+        body_tag: PhysicsRigidBodyTag,
+        direction: Option<Unit<Vector3<N>>>,
+    );
+
+    /// Get the one way direction of the body.
+    fn one_way_direction(&self, body_tag: PhysicsRigidBodyTag) -> Option<Unit<Vector3<N>>>;
+
     /// Set the groups this body belong to.
     fn set_belong_to(&self, body_tag: PhysicsRigidBodyTag, groups: Vec<CollisionGroup>);
 
@@ -126,6 +304,24 @@ pub trait RBodyPhysicsServerTrait<N: crate::PtReal> {
     /// Get the angular velocity of the body
     fn angular_velocity(&self, body: PhysicsRigidBodyTag) -> Vector3<N>;
 
+    /// Set the maximum linear velocity of the body.
+    ///
+    /// The velocity is clamped by the physics backend at the end of each step.
+    /// Passing None, the velocity is not limited.
+    fn set_max_linear_velocity(&self, body: PhysicsRigidBodyTag, max: Option<N>);
+
+    /// Get the maximum linear velocity of the body.
+    fn max_linear_velocity(&self, body: PhysicsRigidBodyTag) -> Option<N>;
+
+    /// Set the maximum angular velocity of the body.
+    ///
+    /// The velocity is clamped by the physics backend at the end of each step.
+    /// Passing None, the velocity is not limited.
+    fn set_max_angular_velocity(&self, body: PhysicsRigidBodyTag, max: Option<N>);
+
+    /// Get the maximum angular velocity of the body.
+    fn max_angular_velocity(&self, body: PhysicsRigidBodyTag) -> Option<N>;
+
     /// Returns the linear velocity at a give position
     fn linear_velocity_at_position(
         &self,
@@ -146,23 +342,49 @@ pub trait RBodyPhysicsServerTrait<N: crate::PtReal> {
     fn contacts_to_report(&self, body_tag: PhysicsRigidBodyTag) -> usize;
 
     /// Fills the contacts array with the contacts events occurred in the last step.
-    /// It doesn't fill more than the `max_contact_count` set.
+    /// It doesn't fill more than the `max_contact_count` set with `Started` and `Persisted` events;
+    /// the `Ended` events are not counted, so each reported contact always ends.
     ///
     /// It's mandatory to check this array each sub step to be sure to not miss any event.
+    ///
+    /// Each contact is reported with the phase `Started` the first step it's detected, `Persisted`
+    /// the following steps, and `Ended` once, the step it disappears.
     fn contact_events(&self, body_tag: PhysicsRigidBodyTag, contacts: &mut Vec<ContactEvent<N>>);
 }
 
 /// This structure holds all information about the Rigid body before it is created.
 #[derive(Debug)]
-pub struct RigidBodyDesc<N> {
+pub struct RigidBodyDesc<N: crate::PtReal> {
     /// Body mode
     pub mode: BodyMode,
     /// Body mass
     pub mass: N,
+    /// Body density, when set the mass is computed from the shape and `mass` is ignored.
+    pub density: Option<N>,
+    /// Body center of mass in local space, None to compute it from the shape.
+    pub center_of_mass: Option<Point3<N>>,
+    /// Body principal inertia tensor, None to compute it from the shape.
+    pub principal_inertia: Option<Vector3<N>>,
     /// Body friction range 0 - 1
     pub friction: N,
     /// Body bounciness range 0 - 1
     pub bounciness: N,
+    /// Body linear damping
+    pub linear_damping: N,
+    /// Body angular damping
+    pub angular_damping: N,
+    /// Body gravity scale
+    pub gravity_scale: N,
+    /// Body gravity, None to use the world gravity.
+    pub gravity_override: Option<Vector3<N>>,
+    /// Max linear velocity, None to not limit it.
+    pub max_linear_velocity: Option<N>,
+    /// Max angular velocity, None to not limit it.
+    pub max_angular_velocity: Option<N>,
+    /// The body reports the contacts without any collision response.
+    pub is_sensor: bool,
+    /// The local direction along which the other bodies pass through, None to collide from all the sides.
+    pub one_way_direction: Option<Unit<Vector3<N>>>,
     /// Collision Groups this Rigid Body belong.
     pub belong_to: Vec<CollisionGroup>,
     /// Collide with groups.
@@ -181,14 +403,31 @@ pub struct RigidBodyDesc<N> {
     pub lock_rotation_z: bool,
     /// Contacts to report.
     pub contacts_to_report: usize,
+    /// The body can sleep.
+    pub can_sleep: bool,
+    /// Continuous collision detection.
+    pub ccd_enabled: bool,
+    /// The continuous collision detection motion threshold, None to use the backend default.
+    pub ccd_motion_threshold: Option<N>,
 }
 
 /// Initialize the description with default values:
 /// ```ignore
 /// mode: BodyMode::Dynamic,
 /// mass: 1.0,
+/// density: None,
+/// center_of_mass: None,
+/// principal_inertia: None,
 /// friction: 0.2,
 /// bounciness: 0.0,
+/// linear_damping: 0.0,
+/// angular_damping: 0.0,
+/// gravity_scale: 1.0,
+/// gravity_override: None,
+/// max_linear_velocity: None,
+/// max_angular_velocity: None,
+/// is_sensor: false,
+/// one_way_direction: None,
 /// belong_to: vec(1),
 /// collide_with: vec(1),
 /// lock_translation_x: false,
@@ -198,14 +437,28 @@ pub struct RigidBodyDesc<N> {
 /// lock_rotation_y: false,
 /// lock_rotation_z: false,
 /// contacts_to_report: 0,
+/// can_sleep: true,
+/// ccd_enabled: false,
+/// ccd_motion_threshold: None,
 /// ```
 impl<N: crate::PtReal> Default for RigidBodyDesc<N> {
     fn default() -> Self {
         RigidBodyDesc {
             mode: BodyMode::default(),
             mass: one(),
+            density: None,
+            center_of_mass: None,
+            principal_inertia: None,
             friction: convert(0.2),
             bounciness: zero(),
+            linear_damping: zero(),
+            angular_damping: zero(),
+            gravity_scale: one(),
+            gravity_override: None,
+            max_linear_velocity: None,
+            max_angular_velocity: None,
+            is_sensor: false,
+            one_way_direction: None,
             belong_to: vec![CollisionGroup::default()],
             collide_with: vec![CollisionGroup::default()],
             lock_translation_x: false,
@@ -215,6 +468,9 @@ impl<N: crate::PtReal> Default for RigidBodyDesc<N> {
             lock_rotation_y: false,
             lock_rotation_z: false,
             contacts_to_report: 0,
+            can_sleep: true,
+            ccd_enabled: false,
+            ccd_motion_threshold: None,
         }
     }
 }
@@ -241,6 +497,10 @@ impl Default for BodyMode {
 /// A contact event generated in the past frame.
 #[derive(Copy, Clone, PartialEq, Debug)]
 pub struct ContactEvent<N: crate::PtReal> {
+    /// The contact phase.
+    pub phase: ContactPhase,
+    /// The contact ID, that stays the same while the contact persists.
+    pub id: u64,
     /// The other body tag.
     pub other_body: PhysicsRigidBodyTag,
     /// The other body entity.
@@ -249,18 +509,76 @@ pub struct ContactEvent<N: crate::PtReal> {
     pub normal: Unit<Vector3<N>>,
     /// The contact location in world space.
     pub location: Point3<N>,
+    /// The contact location on the local body, in local space.
+    pub local_location: Point3<N>,
+    /// The contact location on the other body, in the other body local space.
+    pub other_local_location: Point3<N>,
+    /// The penetration depth.
+    pub depth: N,
     /// The generated impulse.
     pub impulse: Vector3<N>,
+    /// The velocity of the other body relative to the local body, at the contact location.
+    pub relative_velocity: Vector3<N>,
+    /// The index of the local body sub shape, for the `Compound` and `TriMesh` shapes.
+    pub sub_shape: Option<usize>,
+    /// The index of the other body sub shape, for the `Compound` and `TriMesh` shapes.
+    pub other_sub_shape: Option<usize>,
+    /// The material of the other body surface, at the contact location.
+    pub other_material: Option<PhysicsMaterialTag>,
+    /// The user tag of the other body sub shape, at the contact location.
+    ///
+    /// It's Some only when the touched sub shape has a `SubShapeMaterial`.
+    pub other_user_tag: Option<u64>,
 }
 
 impl<N: crate::PtReal> Default for ContactEvent<N> {
     fn default() -> Self {
         ContactEvent {
+            phase: ContactPhase::Started,
+            id: 0,
             other_body: PhysicsRigidBodyTag::U32(0),
             other_entity: None,
             normal: Vector3::y_axis(),
             location: Point3::origin(),
+            local_location: Point3::origin(),
+            other_local_location: Point3::origin(),
+            depth: zero(),
             impulse: Vector3::zeros(),
+            relative_velocity: Vector3::zeros(),
+            sub_shape: None,
+            other_sub_shape: None,
+            other_material: None,
+            other_user_tag: None,
         }
     }
 }
+
+/// The result of a motion test, when the motion collides.
+///
+/// It's possible to get it using the function `test_motion`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct MotionTestResult<N: crate::PtReal> {
+    /// The fraction of the motion that can be performed safely, range 0 - 1.
+    pub safe_fraction: N,
+    /// The motion that can't be performed because of the collision.
+    pub remainder: Vector3<N>,
+    /// The collided body tag.
+    pub other_body: PhysicsRigidBodyTag,
+    /// The collided body entity.
+    pub other_entity: Option<Entity>,
+    /// The collision normal, pointing toward the body.
+    pub normal: Unit<Vector3<N>>,
+    /// The collision location in world space.
+    pub location: Point3<N>,
+}
+
+/// The phase of a contact.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum ContactPhase {
+    /// The contact started in the last step.
+    Started,
+    /// The contact was already there in the previous step.
+    Persisted,
+    /// The contact ended in the last step.
+    Ended,
+}
@@ -0,0 +1,74 @@
+use amethyst_core::math::{convert, zero};
+
+use crate::objects::*;
+
+/// This is the interface used to manipulate the physics materials.
+///
+/// A material can be shared by many bodies; changing it, changes the surface of all of them.
+/// The object that implement this interface is implemented by `MaterialPhysicsServer`.
+/// It's stored as resource in the world.
+pub trait MaterialPhysicsServerTrait<N: crate::PtReal> {
+    /// Create a material and return the handle to it.
+    /// The PhysicsHandle returned can be safely cloned.
+    /// When all instances of this Handle are dropped the material is Dropped automatically.
+    fn create(&self, material_desc: &MaterialDesc<N>) -> PhysicsHandle<PhysicsMaterialTag>;
+
+    /// Change the internal description of this material.
+    ///
+    /// All the bodies that use this material are updated.
+    fn update(&self, material_tag: PhysicsMaterialTag, material_desc: &MaterialDesc<N>);
+}
+
+/// Material description used to create a new material using `create`.
+#[derive(Copy, Clone, Debug)]
+pub struct MaterialDesc<N: crate::PtReal> {
+    /// Friction range 0 - 1
+    pub friction: N,
+    /// Bounciness (restitution) range 0 - 1
+    pub bounciness: N,
+    /// Rolling friction
+    pub rolling_friction: N,
+    /// How the friction of the two touching surfaces is combined.
+    pub friction_combine: CombineMode,
+    /// How the bounciness of the two touching surfaces is combined.
+    pub bounciness_combine: CombineMode,
+}
+
+/// Initialize the description with default values:
+/// ```ignore
+/// friction: 0.2,
+/// bounciness: 0.0,
+/// rolling_friction: 0.0,
+/// friction_combine: CombineMode::Average,
+/// bounciness_combine: CombineMode::Average,
+/// ```
+impl<N: crate::PtReal> Default for MaterialDesc<N> {
+    fn default() -> Self {
+        MaterialDesc {
+            friction: convert(0.2),
+            bounciness: zero(),
+            rolling_friction: zero(),
+            friction_combine: CombineMode::default(),
+            bounciness_combine: CombineMode::default(),
+        }
+    }
+}
+
+/// The rule used to combine a coefficient of two touching surfaces.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum CombineMode {
+    /// The average of the two coefficients.
+    Average,
+    /// The smallest of the two coefficients.
+    Min,
+    /// The biggest of the two coefficients.
+    Max,
+    /// The product of the two coefficients.
+    Multiply,
+}
+
+impl Default for CombineMode {
+    fn default() -> Self {
+        CombineMode::Average
+    }
+}
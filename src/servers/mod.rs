@@ -6,14 +6,22 @@
 //! - [RBody Server](trait.RBodyPhysicsServerTrait.html)
 //! - [Area Server](trait.AreaPhysicsServerTrait.html)
 //! - [Shape Server](trait.ShapePhysicsServerTrait.html)
+//! - [Material Server](trait.MaterialPhysicsServerTrait.html)
 //!
 //! Is it possible to access them trough the `PhysicsWorld`.
 
-pub use area_server::{AreaDesc, AreaPhysicsServerTrait, OverlapEvent};
-pub use body_server::{BodyMode, ContactEvent, RBodyPhysicsServerTrait, RigidBodyDesc};
+pub use area_server::{
+    AreaDesc, AreaGravity, AreaOverrideMode, AreaPhysicsServerTrait, FluidDesc, OverlapEvent,
+};
+pub use body_server::{
+    BodyMode, ContactEvent, ContactPhase, MotionTestResult, RBodyPhysicsServerTrait, RigidBodyDesc,
+};
 pub use joint_server::{JointDesc, JointPhysicsServerTrait, JointPosition};
-pub use shape_server::{ShapeDesc, ShapePhysicsServerTrait};
-pub use world_server::WorldPhysicsServerTrait;
+pub use material_server::{CombineMode, MaterialDesc, MaterialPhysicsServerTrait};
+pub use shape_server::{ShapeDesc, ShapePhysicsServerTrait, SubShapeMaterial};
+pub use world_server::{
+    PointProjectionResult, RayCastResult, ShapeCastResult, WorldPhysicsServerTrait, WorldSettings,
+};
 
 /// This struct contains all the servers that can be used to control a `PhysicsEngine`.
 ///
@@ -26,6 +34,7 @@ pub struct PhysicsWorld<N> {
     area_server: Box<dyn AreaPhysicsServerTrait<N>>,
     shape_server: Box<dyn ShapePhysicsServerTrait<N>>,
     joint_server: Box<dyn JointPhysicsServerTrait<N>>,
+    material_server: Box<dyn MaterialPhysicsServerTrait<N>>,
 }
 
 impl<N> PhysicsWorld<N> {
@@ -38,6 +47,7 @@ impl<N> PhysicsWorld<N> {
         area_server: Box<dyn AreaPhysicsServerTrait<N>>,
         shape_server: Box<dyn ShapePhysicsServerTrait<N>>,
         joint_server: Box<dyn JointPhysicsServerTrait<N>>,
+        material_server: Box<dyn MaterialPhysicsServerTrait<N>>,
     ) -> Self {
         PhysicsWorld {
             world_server,
@@ -45,6 +55,7 @@ impl<N> PhysicsWorld<N> {
             area_server,
             shape_server,
             joint_server,
+            material_server,
         }
     }
 
@@ -72,6 +83,11 @@ impl<N> PhysicsWorld<N> {
     pub fn joint_server(&self) -> &dyn JointPhysicsServerTrait<N> {
         self.joint_server.as_ref()
     }
+
+    /// Return material server.
+    pub fn material_server(&self) -> &dyn MaterialPhysicsServerTrait<N> {
+        self.material_server.as_ref()
+    }
 }
 
 unsafe impl<N> Send for PhysicsWorld<N> {}
@@ -80,5 +96,6 @@ unsafe impl<N> Sync for PhysicsWorld<N> {}
 mod area_server;
 mod body_server;
 mod joint_server;
+mod material_server;
 mod shape_server;
 mod world_server;
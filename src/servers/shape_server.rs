@@ -56,10 +56,37 @@ pub enum ShapeDesc<N: crate::PtReal> {
         points: Vec<Point3<N>>,
         /// Triangle indices
         indices: Vec<Point3<usize>>,
+        /// The materials used by the triangles
+        materials: Vec<SubShapeMaterial>,
+        /// The index, in `materials`, of the material of each triangle.
+        /// Leave it empty to use the body material.
+        ///
+        /// When not empty, its length should be equal to the `indices` length, and each index should
+        /// be lower than the `materials` length. The triangles without a valid index use the body
+        /// material, and the physics backend may log an error.
+        material_indices: Vec<usize>,
     },
     /// A shape composed of other shapes
     Compound {
         /// Vector of shapes
         shapes: Vec<(Isometry3<N>, ShapeDesc<N>)>,
+        /// The material of each shape.
+        /// Leave it empty to use the body material.
+        ///
+        /// When not empty, its length should be equal to the `shapes` length. The shapes without a
+        /// material use the body material, and the physics backend may log an error.
+        materials: Vec<SubShapeMaterial>,
     },
 }
+
+/// The surface description of a part of a shape, like a triangle of a `TriMesh` or a child of a
+/// `Compound`.
+///
+/// When a contact happens on this part, its material and user tag are reported in the `ContactEvent`.
+#[derive(Clone, Debug, Default)]
+pub struct SubShapeMaterial {
+    /// The material, None to use the body material.
+    pub material: Option<PhysicsHandle<PhysicsMaterialTag>>,
+    /// A user defined tag, useful to identify the surface (like mud or ice).
+    pub user_tag: u64,
+}
@@ -1,6 +1,7 @@
-use amethyst_core::math::Vector3;
+use amethyst_core::ecs::Entity;
+use amethyst_core::math::{convert, one, Isometry3, Point3, Unit, Vector3};
 
-use crate::PtReal;
+use crate::{objects::*, PtReal};
 
 /// This is the interface that contains all functionalities to manipulate the world.
 /// The object that implement this interface is wrapped by `WorldPhysicsServer`.
@@ -18,4 +19,210 @@ pub trait WorldPhysicsServerTrait<N: PtReal> {
 
     /// get world gravity
     fn gravity(&self) -> Vector3<N>;
+
+    /// Set the world solver and integration settings.
+    fn set_settings(&self, settings: &WorldSettings<N>);
+
+    /// Get the world solver and integration settings.
+    fn settings(&self) -> WorldSettings<N>;
+
+    /// Cast a ray from `from` to `to` and returns the closest hit, if any.
+    ///
+    /// Only the objects that belong to at least one of the `collide_with` groups are considered;
+    /// both rigid bodies and areas are reported.
+    fn ray_cast(
+        &self,
+        from: &Point3<N>,
+        to: &Point3<N>,
+        collide_with: Vec<CollisionGroup>,
+    ) -> Option<RayCastResult<N>>;
+
+    /// Cast a ray from `from` to `to` and returns all the hits, sorted by distance.
+    ///
+    /// Only the objects that belong to at least one of the `collide_with` groups are considered;
+    /// both rigid bodies and areas are reported.
+    fn ray_cast_all(
+        &self,
+        from: &Point3<N>,
+        to: &Point3<N>,
+        collide_with: Vec<CollisionGroup>,
+    ) -> Vec<RayCastResult<N>>;
+
+    /// Sweep the shape from the `from` transformation along `motion`, and returns the first hit, if any.
+    ///
+    /// The shape is moved of the full `motion` vector, so its length is the maximum cast distance.
+    ///
+    /// Only the objects that belong to at least one of the `collide_with` groups are considered;
+    /// both rigid bodies and areas are reported.
+    ///
+    /// You can create a shape, using the function `ShapeServer::create`.
+    fn shape_cast(
+        &self,
+        shape_tag: PhysicsShapeTag,
+        from: &Isometry3<N>,
+        motion: &Vector3<N>,
+        collide_with: Vec<CollisionGroup>,
+    ) -> Option<ShapeCastResult<N>>;
+
+    /// Returns all the objects that overlap the shape, placed with the given transformation.
+    ///
+    /// This query is immediate; unlike the areas, it's not necessary to wait a step to get the result.
+    ///
+    /// Only the objects that belong to at least one of the `collide_with` groups are considered;
+    /// both rigid bodies and areas are reported.
+    ///
+    /// You can create a shape, using the function `ShapeServer::create`.
+    fn overlap(
+        &self,
+        shape_tag: PhysicsShapeTag,
+        transf: &Isometry3<N>,
+        collide_with: Vec<CollisionGroup>,
+    ) -> Vec<(PhysicsObjectTag, Option<Entity>)>;
+
+    /// Returns all the objects whose shape contains the point.
+    ///
+    /// Only the objects that belong to at least one of the `collide_with` groups are considered;
+    /// both rigid bodies and areas are reported.
+    fn point_query(
+        &self,
+        point: &Point3<N>,
+        collide_with: Vec<CollisionGroup>,
+    ) -> Vec<(PhysicsObjectTag, Option<Entity>)>;
+
+    /// Project the point on the closest object surface, and returns the projection if any object
+    /// is within `max_distance`.
+    ///
+    /// Only the objects that belong to at least one of the `collide_with` groups are considered;
+    /// both rigid bodies and areas are reported.
+    fn project_point(
+        &self,
+        point: &Point3<N>,
+        collide_with: Vec<CollisionGroup>,
+        max_distance: N,
+    ) -> Option<PointProjectionResult<N>>;
+
+    /// Returns all the objects whose bounding box intersects the region delimited by `mins` and `maxs`.
+    ///
+    /// This query doesn't perform any narrow phase check, so it's cheap but not precise; use the
+    /// `aabb` function of the body and area servers to refine the result.
+    ///
+    /// Only the objects that belong to at least one of the `collide_with` groups are considered;
+    /// both rigid bodies and areas are reported.
+    fn aabb_query(
+        &self,
+        mins: &Point3<N>,
+        maxs: &Point3<N>,
+        collide_with: Vec<CollisionGroup>,
+    ) -> Vec<(PhysicsObjectTag, Option<Entity>)>;
+}
+
+/// This structure holds the solver and integration parameters of the world.
+///
+/// It's possible to set them using the function `set_settings`, or through the `PhysicsBundle`.
+#[derive(Copy, Clone, Debug)]
+pub struct WorldSettings<N: PtReal> {
+    /// Number of iterations performed by the velocity solver.
+    pub velocity_iterations: usize,
+    /// Number of iterations performed by the position solver.
+    pub position_iterations: usize,
+    /// The penetration depth that is allowed without being corrected.
+    pub allowed_linear_error: N,
+    /// The maximum penetration correction performed in a single step.
+    pub max_penetration_correction: N,
+    /// The factor applied to the previous step impulses, to warm start the solver. Range 0 - 1
+    pub warm_start_factor: N,
+    /// The relative velocity under which the restitution is ignored.
+    pub restitution_velocity_threshold: N,
+    /// The linear velocity under which a body is considered at rest.
+    pub sleep_linear_velocity_threshold: N,
+    /// The angular velocity under which a body is considered at rest.
+    pub sleep_angular_velocity_threshold: N,
+    /// The time, in seconds, that a body has to stay at rest before going to sleep.
+    pub time_before_sleep: N,
+}
+
+/// Initialize the settings with default values:
+/// ```ignore
+/// velocity_iterations: 8,
+/// position_iterations: 3,
+/// allowed_linear_error: 0.001,
+/// max_penetration_correction: 0.2,
+/// warm_start_factor: 1.0,
+/// restitution_velocity_threshold: 1.0,
+/// sleep_linear_velocity_threshold: 0.1,
+/// sleep_angular_velocity_threshold: 0.14,
+/// time_before_sleep: 0.5,
+/// ```
+///
+/// Each physics backend has its own defaults, that may differ from these values; to change only
+/// some parameters, start from the backend settings returned by `settings`.
+impl<N: PtReal> Default for WorldSettings<N> {
+    fn default() -> Self {
+        WorldSettings {
+            velocity_iterations: 8,
+            position_iterations: 3,
+            allowed_linear_error: convert(0.001),
+            max_penetration_correction: convert(0.2),
+            warm_start_factor: one(),
+            restitution_velocity_threshold: one(),
+            sleep_linear_velocity_threshold: convert(0.1),
+            sleep_angular_velocity_threshold: convert(0.14),
+            time_before_sleep: convert(0.5),
+        }
+    }
+}
+
+/// The result of a ray cast.
+///
+/// It's possible to get it using the functions `ray_cast` and `ray_cast_all`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct RayCastResult<N: PtReal> {
+    /// The hit object tag.
+    pub object: PhysicsObjectTag,
+    /// The hit object entity.
+    pub entity: Option<Entity>,
+    /// The hit location in world space.
+    pub location: Point3<N>,
+    /// The surface normal at the hit location.
+    pub normal: Unit<Vector3<N>>,
+    /// The distance between the ray origin and the hit location.
+    pub distance: N,
+}
+
+/// The result of a shape cast.
+///
+/// It's possible to get it using the function `shape_cast`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct ShapeCastResult<N: PtReal> {
+    /// The hit object tag.
+    pub object: PhysicsObjectTag,
+    /// The hit object entity.
+    pub entity: Option<Entity>,
+    /// The time of impact, range 0 - 1.
+    ///
+    /// It's the fraction of the `motion` that the shape can travel before the hit.
+    pub time_of_impact: N,
+    /// The contact location in world space, at the time of impact.
+    pub location: Point3<N>,
+    /// The surface normal at the contact location.
+    pub normal: Unit<Vector3<N>>,
+}
+
+/// The result of a point projection.
+///
+/// It's possible to get it using the function `project_point`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct PointProjectionResult<N: PtReal> {
+    /// The object tag.
+    pub object: PhysicsObjectTag,
+    /// The object entity.
+    pub entity: Option<Entity>,
+    /// The projected point on the object surface, in world space.
+    pub location: Point3<N>,
+    /// The surface normal at the projected location.
+    pub normal: Unit<Vector3<N>>,
+    /// The distance between the point and its projection.
+    pub distance: N,
+    /// True when the point is inside the object shape.
+    pub is_inside: bool,
 }
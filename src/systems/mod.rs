@@ -4,6 +4,7 @@ pub use physics_bundle::PhysicsBundle;
 pub use physics_stepper_system::PhysicsStepperSystem;
 pub use physics_sync_entity_system::PhysicsSyncEntitySystem;
 pub use physics_sync_joint_system::PhysicsSyncJointSystem;
+pub use physics_sync_material_system::PhysicsSyncMaterialSystem;
 pub use physics_sync_shape_system::PhysicsSyncShapeSystem;
 pub use physics_sync_transform_from_system::PhysicsSyncTransformFromSystem;
 pub use physics_sync_transform_to_system::PhysicsSyncTransformToSystem;
@@ -14,6 +15,7 @@ mod physics_bundle;
 mod physics_stepper_system;
 mod physics_sync_entity_system;
 mod physics_sync_joint_system;
+mod physics_sync_material_system;
 mod physics_sync_shape_system;
 mod physics_sync_transform_from_system;
 mod physics_sync_transform_to_system;
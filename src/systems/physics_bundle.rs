@@ -8,10 +8,11 @@ use log::info;
 
 use crate::{
     objects::PhysicsSetupStorages,
+    servers::WorldSettings,
     systems::{
         PhysicsAttachmentSystem, PhysicsBatchSystem, PhysicsStepperSystem, PhysicsSyncEntitySystem,
-        PhysicsSyncJointSystem, PhysicsSyncShapeSystem, PhysicsSyncTransformFromSystem,
-        PhysicsSyncTransformToSystem,
+        PhysicsSyncJointSystem, PhysicsSyncMaterialSystem, PhysicsSyncShapeSystem,
+        PhysicsSyncTransformFromSystem, PhysicsSyncTransformToSystem,
     },
     PhysicsTime,
 };
@@ -27,7 +28,8 @@ use crate::{
 ///
 /// ```
 /// Is it possible to define the Physics Engine floating point precision and the [PhysicsBackend](./trait.PhysicsBackend.html);
-/// additionally, the physics frame rate can be specified using the function `with_frames_per_seconds`.
+/// additionally, the physics frame rate can be specified using the function `with_frames_per_seconds`,
+/// and the solver parameters using the function `with_world_settings`.
 ///
 /// # Dispatcher pipeline
 ///
@@ -130,6 +132,8 @@ pub struct PhysicsBundle<'a, 'b, N: crate::PtReal, B: crate::PhysicsBackend<N>>
     phantom_data_float: std::marker::PhantomData<N>,
     phantom_data_backend: std::marker::PhantomData<B>,
     physics_time: PhysicsTime,
+    #[allow(clippy::type_complexity)]
+    world_settings: Vec<Box<dyn FnOnce(&mut WorldSettings<N>)>>,
     physics_builder: DispatcherBuilder<'a, 'b>,
     pre_physics_dispatcher_operations: Vec<Box<dyn DispatcherOperation<'a, 'b>>>,
     in_physics_dispatcher_operations: Vec<Box<dyn DispatcherOperation<'a, 'b>>>,
@@ -261,6 +265,7 @@ impl<'a, 'b, N: crate::PtReal, B: crate::PhysicsBackend<N>> PhysicsBundle<'a, 'b
             phantom_data_float: std::marker::PhantomData,
             phantom_data_backend: std::marker::PhantomData,
             physics_time: PhysicsTime::default(),
+            world_settings: Vec::new(),
             physics_builder: DispatcherBuilder::new(),
             pre_physics_dispatcher_operations: Vec::new(),
             in_physics_dispatcher_operations: Vec::new(),
@@ -314,6 +319,41 @@ impl<'a, 'b, N: crate::PtReal, B: crate::PhysicsBackend<N>> PhysicsBundle<'a, 'b
         self.physics_time.set_max_sub_steps(max_sub_steps);
     }
 
+    /// Set the world solver and integration settings.
+    ///
+    /// The function receives the physics backend settings, so only the changed parameters are
+    /// overridden; when called many times, the functions are applied in the calls order:
+    /// ```rust,ignore
+    /// bundle.with_world_settings(|settings| settings.velocity_iterations = 20)
+    /// ```
+    /// This is just an helper function, and you can modify it later in the game.
+    ///
+    /// Check the [WorldSettings](./servers/struct.WorldSettings.html)
+    pub fn with_world_settings<F>(mut self, world_settings: F) -> Self
+    where
+        F: FnOnce(&mut WorldSettings<N>) + 'static,
+    {
+        self.set_world_settings(world_settings);
+        self
+    }
+
+    /// Set the world solver and integration settings.
+    ///
+    /// The function receives the physics backend settings, so only the changed parameters are
+    /// overridden; when called many times, the functions are applied in the calls order:
+    /// ```rust,ignore
+    /// bundle.set_world_settings(|settings| settings.velocity_iterations = 20)
+    /// ```
+    /// This is just an helper function, and you can modify it later in the game.
+    ///
+    /// Check the [WorldSettings](./servers/struct.WorldSettings.html)
+    pub fn set_world_settings<F>(&mut self, world_settings: F)
+    where
+        F: FnOnce(&mut WorldSettings<N>) + 'static,
+    {
+        self.world_settings.push(Box::new(world_settings));
+    }
+
     define_setters!(
         /// Add a `System` to the **Pre physics** pipeline.
         ///
@@ -415,7 +455,15 @@ where
     ) -> Result<(), Error> {
         PhysicsSetupStorages::setup(world);
 
-        world.insert(B::create_world());
+        let physics_world = B::create_world();
+        if !self.world_settings.is_empty() {
+            let mut settings = physics_world.world_server().settings();
+            for world_settings in self.world_settings {
+                world_settings(&mut settings);
+            }
+            physics_world.world_server().set_settings(&settings);
+        }
+        world.insert(physics_world);
         world.insert(self.physics_time);
 
         let physics_builder = {
@@ -472,6 +520,11 @@ where
             "physics_sync_shape",
             &[],
         );
+        builder.add(
+            PhysicsSyncMaterialSystem::<N>::default(),
+            "physics_sync_material",
+            &[],
+        );
         builder.add(
             PhysicsSyncTransformToSystem::<N>::new(),
             "physics_sync_transform_to",
@@ -498,6 +551,7 @@ where
             "physics_batch",
             &[
                 "physics_sync_shape",
+                "physics_sync_material",
                 "physics_sync_joint",
                 "physics_sync_entity",
                 "physics_sync_transform_to",
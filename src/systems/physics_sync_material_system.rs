@@ -0,0 +1,85 @@
+use amethyst_core::ecs::{prelude::*, storage::ComponentEvent, ReaderId};
+
+use crate::prelude::*;
+
+/// Thanks to this `System`, it is enough to set a material as a `Component` of an `Entity`, to use it
+/// as a rigid body material.
+/// Here, the automatic association of the `Material` to the `RigidBody` is managed.
+pub struct PhysicsSyncMaterialSystem<N: crate::PtReal> {
+    phantom_data: std::marker::PhantomData<N>,
+    bodies_event_reader: Option<ReaderId<ComponentEvent>>,
+    materials_event_reader: Option<ReaderId<ComponentEvent>>,
+}
+
+impl<N: crate::PtReal> Default for PhysicsSyncMaterialSystem<N> {
+    fn default() -> Self {
+        PhysicsSyncMaterialSystem {
+            phantom_data: std::marker::PhantomData,
+            bodies_event_reader: None,
+            materials_event_reader: None,
+        }
+    }
+}
+
+impl<'a, N: crate::PtReal> System<'a> for PhysicsSyncMaterialSystem<N> {
+    #[allow(clippy::type_complexity)]
+    type SystemData = (
+        ReadExpect<'a, PhysicsWorld<N>>,
+        ReadStorage<'a, PhysicsHandle<PhysicsRigidBodyTag>>,
+        ReadStorage<'a, PhysicsHandle<PhysicsMaterialTag>>,
+    );
+
+    fn run(&mut self, (physics_world, bodies, materials): Self::SystemData) {
+        // Synchronize the `Materials` with `RigidBodies`
+        // Contains the entity ID of which need to update the material information
+        let dirty_materials = {
+            let bodies_events = bodies
+                .channel()
+                .read(self.bodies_event_reader.as_mut().unwrap());
+            let materials_events = materials
+                .channel()
+                .read(self.materials_event_reader.as_mut().unwrap());
+
+            let mut dirty_materials =
+                BitSet::with_capacity((bodies_events.len() + materials_events.len()) as u32);
+
+            bodies_events.chain(materials_events).for_each(|e| match e {
+                ComponentEvent::Inserted(index)
+                | ComponentEvent::Modified(index)
+                | ComponentEvent::Removed(index) => {
+                    dirty_materials.add(*index);
+                }
+            });
+
+            dirty_materials
+        };
+
+        // Insert or Update material to `RigidBody`
+        for (body, material, _) in (&bodies, &materials, &dirty_materials).join() {
+            physics_world
+                .rigid_body_server()
+                .set_material(body.get(), Some(material.get()));
+        }
+
+        // Remove material to `RigidBody`
+        for (body, _, _) in (&bodies, !&materials, &dirty_materials).join() {
+            physics_world
+                .rigid_body_server()
+                .set_material(body.get(), None);
+        }
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+        {
+            let mut storage: WriteStorage<'_, PhysicsHandle<PhysicsRigidBodyTag>> =
+                SystemData::fetch(world);
+            self.bodies_event_reader = Some(storage.register_reader());
+        }
+        {
+            let mut storage: WriteStorage<'_, PhysicsHandle<PhysicsMaterialTag>> =
+                SystemData::fetch(world);
+            self.materials_event_reader = Some(storage.register_reader());
+        }
+    }
+}
@@ -1,5 +1,11 @@
+use std::collections::HashMap;
+
 use amethyst_core::{
-    ecs::{Entities, Join, ReadExpect, ReadStorage, System, WriteStorage},
+    ecs::{
+        storage::ComponentEvent, world::Index, Entities, Join, ReadExpect, ReadStorage, ReaderId,
+        System, SystemData, World, WriteStorage,
+    },
+    math::Isometry3,
     transform::components::{Parent, Transform},
 };
 
@@ -12,17 +18,26 @@ use crate::{conversors, objects::*, servers::*};
 /// This `System` runs once per frame, and synchronize the `Transform` component.
 /// The `Transform` `component` is used to position an entity inside the world.
 /// - The physics engine position is copied in the `Transform` component.
+/// - The sleeping bodies are skipped when their transformation is the one already synchronized,
+///   so their `Transform` is not flagged as modified each frame.
 ///
 /// This `System` runs at the beginning of the Physics Frame, in order to allow the rendering to run
 /// in parallel with the the *physics engine* stepping.
 pub struct PhysicsSyncTransformFromSystem<N: crate::PtReal> {
     phantom_data: std::marker::PhantomData<N>,
+    /// The last transformation synchronized to each sleeping body entity.
+    synced_sleeping_bodies: HashMap<Index, Isometry3<N>>,
+    bodies_event_reader: Option<ReaderId<ComponentEvent>>,
+    transforms_event_reader: Option<ReaderId<ComponentEvent>>,
 }
 
 impl<N: crate::PtReal> PhysicsSyncTransformFromSystem<N> {
     pub fn new() -> PhysicsSyncTransformFromSystem<N> {
         PhysicsSyncTransformFromSystem {
             phantom_data: std::marker::PhantomData,
+            synced_sleeping_bodies: HashMap::new(),
+            bodies_event_reader: None,
+            transforms_event_reader: None,
         }
     }
 }
@@ -41,14 +56,49 @@ impl<'s, N: crate::PtReal> System<'s> for PhysicsSyncTransformFromSystem<N> {
         &mut self,
         (entities, physics_world, mut transforms, bodies, parents): Self::SystemData,
     ) {
+        // A new or removed body handle or `Transform` must be synchronized again, even if the body
+        // is sleeping.
+        bodies
+            .channel()
+            .read(self.bodies_event_reader.as_mut().unwrap())
+            .for_each(|e| match e {
+                ComponentEvent::Inserted(index)
+                | ComponentEvent::Modified(index)
+                | ComponentEvent::Removed(index) => {
+                    self.synced_sleeping_bodies.remove(index);
+                }
+            });
+        transforms
+            .channel()
+            .read(self.transforms_event_reader.as_mut().unwrap())
+            .for_each(|e| match e {
+                ComponentEvent::Inserted(index) | ComponentEvent::Removed(index) => {
+                    self.synced_sleeping_bodies.remove(index);
+                }
+                // Modified by this `System` or by the user, that moves the body through the
+                // `PhysicsSyncTransformToSystem`.
+                ComponentEvent::Modified(_) => {}
+            });
+
         let transf_mask = transforms.mask().clone(); // NOTE: that the transformation are modified in this way to avoid to mutate the Transform component entirely.
 
         // Sync physics engine transform back to Amethyst.
         // TODO find a way to update only moving things and not always all
         for (entity, rb, _, _) in (&entities, &bodies, &transf_mask, !&parents).join() {
-            if let Some(transform) = transforms.get_mut(entity) {
-                let body_transform = physics_world.rigid_body_server().transform(rb.get());
+            let body_transform = physics_world.rigid_body_server().transform(rb.get());
+
+            if physics_world.rigid_body_server().is_sleeping(rb.get()) {
+                // The sleeping body is synchronized only if it's moved since the last sync.
+                if self.synced_sleeping_bodies.get(&entity.id()) == Some(&body_transform) {
+                    continue;
+                }
+                self.synced_sleeping_bodies
+                    .insert(entity.id(), body_transform);
+            } else {
+                self.synced_sleeping_bodies.remove(&entity.id());
+            }
 
+            if let Some(transform) = transforms.get_mut(entity) {
                 transform.set_isometry(conversors::transf_conversor::from_physics(&body_transform));
             }
         }
@@ -59,4 +109,17 @@ impl<'s, N: crate::PtReal> System<'s> for PhysicsSyncTransformFromSystem<N> {
         // Runs a Dynamic Rigid Body as parent of another Rigid Body is unexpected behaviour so this
         // case is not integrated
     }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+        {
+            let mut storage: WriteStorage<'_, PhysicsHandle<PhysicsRigidBodyTag>> =
+                SystemData::fetch(world);
+            self.bodies_event_reader = Some(storage.register_reader());
+        }
+        {
+            let mut storage: WriteStorage<'_, Transform> = SystemData::fetch(world);
+            self.transforms_event_reader = Some(storage.register_reader());
+        }
+    }
 }